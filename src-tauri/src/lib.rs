@@ -8,6 +8,23 @@ use tauri_plugin_log::{Target, TargetKind, RotationStrategy};
 use log::{info, error, warn, debug};
 use std::path::PathBuf;
 use tauri_plugin_http::reqwest;
+use std::sync::mpsc;
+use std::time::Duration;
+use base64::{engine::general_purpose, Engine as _};
+
+#[cfg(target_os = "windows")]
+use windows_service::{
+    define_windows_service,
+    service::{
+        ServiceControl, ServiceControlAccept, ServiceExitCode, ServiceState, ServiceStatus,
+        ServiceType,
+    },
+    service_control_handler::{self, ServiceControlHandlerResult},
+    service_dispatcher,
+};
+
+#[cfg(target_os = "windows")]
+const SERVICE_NAME: &str = "SystemMonitorService";
 
 #[derive(Serialize)]
 struct BasicSystemInfo {
@@ -22,6 +39,97 @@ struct WindowsSpecificInfo {
     activation_status: String,
 }
 
+#[derive(Serialize)]
+struct NetworkNeighbor {
+    ip_address: String,
+    mac_address: String,
+    interface: String,
+    state: String,
+}
+
+#[derive(Serialize)]
+struct WifiNetwork {
+    ssid: String,
+    signal_percent: u8,
+    channel: String,
+    security: String,
+}
+
+#[derive(serde::Deserialize)]
+struct OAuthTokenResponse {
+    access_token: String,
+    expires_in: u64,
+    #[allow(dead_code)]
+    token_type: String,
+}
+
+#[derive(Clone)]
+struct CachedToken {
+    access_token: String,
+    expires_at: std::time::Instant,
+}
+
+// Caché global de tokens OAuth2 vigentes, compartida entre llamadas a
+// send_to_api. Se indexa por (token_url, client_id) para que un token
+// cacheado de un backend/cliente no se devuelva por error para otro.
+static OAUTH_TOKEN_CACHE: std::sync::OnceLock<std::sync::Mutex<std::collections::HashMap<(String, String), CachedToken>>> = std::sync::OnceLock::new();
+
+// Margen de seguridad antes de la expiración en el que se fuerza un refresco
+const OAUTH_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct QueuedTelemetryRecord {
+    endpoint: String,
+    payload: String,
+    token: Option<String>,
+    // Momento en que se recolectó el payload (no en que se encoló ni se
+    // reintentó), para que el envelope enviado al drenar refleje cuándo se
+    // tomó la telemetría y no cuánto tardó en salir de la cola.
+    #[serde(default = "now_rfc3339")]
+    collected_at: String,
+    #[serde(default)]
+    attempts: u32,
+    // Momento (RFC3339) a partir del cual este registro puede reintentarse.
+    // Se difiere a través de drenados sucesivos para lograr un backoff
+    // exponencial real entre intentos en vez de bloquear el drenado con
+    // `tokio::time::sleep`.
+    #[serde(default = "now_rfc3339")]
+    next_attempt_at: String,
+}
+
+const TELEMETRY_QUEUE_FILE: &str = "logs/telemetry_queue.jsonl";
+const TELEMETRY_DEAD_LETTER_FILE: &str = "logs/telemetry_dead_letter.jsonl";
+const TELEMETRY_MAX_ATTEMPTS: u32 = 8;
+const TELEMETRY_FLUSH_INTERVAL: Duration = Duration::from_secs(30);
+const TELEMETRY_MAX_BACKOFF: Duration = Duration::from_secs(240);
+
+// Serializa el acceso al archivo de cola de telemetría entre `enqueue_telemetry`
+// (que puede ser invocado desde el frontend en cualquier momento) y el drenado
+// en segundo plano, para que ningún registro se pierda por una escritura
+// concurrente mientras el drenado está en curso.
+static TELEMETRY_QUEUE_LOCK: std::sync::OnceLock<std::sync::Mutex<()>> = std::sync::OnceLock::new();
+
+fn telemetry_queue_lock() -> &'static std::sync::Mutex<()> {
+    TELEMETRY_QUEUE_LOCK.get_or_init(|| std::sync::Mutex::new(()))
+}
+
+// Versión del esquema del sobre (envelope) de telemetría enviado a la API
+const TELEMETRY_SCHEMA_VERSION: u32 = 1;
+
+#[derive(serde::Serialize)]
+struct TelemetryEnvelope {
+    schema_version: u32,
+    collected_at: String,
+    host_id: String,
+    body: serde_json::Value,
+}
+
+// Marca de tiempo RFC3339 (resolución de segundos) del momento actual. Quien
+// la llama decide qué representa (recolección, encolado o próximo intento).
+fn now_rfc3339() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
 // Comando original de ejemplo (puedes mantenerlo o eliminarlo)
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -133,49 +241,1018 @@ async fn check_internet_connectivity() -> Result<bool, String> {
 
 // Comando para enviar datos a una API externa
 #[tauri::command]
-async fn send_to_api(endpoint: String, payload: String, token: Option<String>) -> Result<String, String> {
+async fn send_to_api(
+    endpoint: String,
+    payload: String,
+    // Momento en que se recolectó el payload. Si no se provee (p. ej. cuando
+    // el frontend envía datos recién recolectados de forma directa) se usa
+    // el momento actual, igual que antes.
+    collected_at: Option<String>,
+    token: Option<String>,
+    basic_auth_username: Option<String>,
+    basic_auth_password: Option<String>,
+    oauth_token_url: Option<String>,
+    oauth_client_id: Option<String>,
+    oauth_client_secret: Option<String>,
+    oauth_scope: Option<String>,
+) -> Result<String, String> {
     info!("📡 Enviando datos a API: {}", endpoint);
-    
+
+    // Si se configuró OAuth2, reutilizar/refrescar el token cacheado en vez
+    // del token manual; de lo contrario usar el `token` provisto por el llamante.
+    let bearer_token = match (&oauth_token_url, &oauth_client_id, &oauth_client_secret) {
+        (Some(token_url), Some(client_id), Some(client_secret)) => {
+            Some(get_or_refresh_oauth_token(token_url, client_id, client_secret, oauth_scope.as_deref()).await?)
+        }
+        _ => token,
+    };
+
+    // Envolver el payload del llamante en un sobre versionado para permitir
+    // negociación de esquema con el servidor
+    let host_id = get_hostname().await.unwrap_or_else(|_| "unknown".to_string());
+    let body = serde_json::from_str(&payload).unwrap_or(serde_json::Value::String(payload.clone()));
+    let envelope = TelemetryEnvelope {
+        schema_version: TELEMETRY_SCHEMA_VERSION,
+        collected_at: collected_at.unwrap_or_else(now_rfc3339),
+        host_id,
+        body,
+    };
+    let envelope_body = serde_json::to_string(&envelope).map_err(|e| {
+        error!("Error serializando el sobre de telemetría: {}", e);
+        format!("Error serializando el sobre de telemetría: {}", e)
+    })?;
+
     let client = reqwest::Client::new();
     let mut request = client
         .post(&endpoint)
         .header("Content-Type", "application/json")
-        .header("User-Agent", "SystemMonitor/1.0");
-    
+        .header("User-Agent", "SystemMonitor/1.0")
+        .header("X-SystemMonitor-Schema", TELEMETRY_SCHEMA_VERSION.to_string());
+
     // Agregar token si está presente
-    if let Some(auth_token) = token {
+    if let Some(auth_token) = &bearer_token {
         if !auth_token.is_empty() {
             request = request.header("Authorization", format!("Bearer {}", auth_token));
             debug!("Token de autorización agregado al request");
         }
     }
-    
-    match request.body(payload).send().await {
-        Ok(response) => {
-            let status = response.status();
-            debug!("Respuesta HTTP: {}", status);
-            
-            if status.is_success() {
-                match response.text().await {
-                    Ok(body) => {
-                        info!("✅ Datos enviados exitosamente a la API");
-                        Ok(body)
-                    },
-                    Err(e) => {
-                        error!("Error leyendo respuesta: {}", e);
-                        Err(format!("Error leyendo respuesta: {}", e))
+
+    let response = match request.body(envelope_body.clone()).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            error!("Error en petición HTTP: {}", e);
+            return Err(format!("Error en petición: {}", e));
+        }
+    };
+
+    let status = response.status();
+    debug!("Respuesta HTTP: {}", status);
+
+    if let Some(mismatch) = check_schema_mismatch(response.headers()) {
+        warn!("{}", mismatch);
+        return Err(mismatch);
+    }
+
+    if status.is_success() {
+        return match response.text().await {
+            Ok(body) => {
+                info!("✅ Datos enviados exitosamente a la API");
+                Ok(body)
+            },
+            Err(e) => {
+                error!("Error leyendo respuesta: {}", e);
+                Err(format!("Error leyendo respuesta: {}", e))
+            }
+        };
+    }
+
+    if status.as_u16() == 401 {
+        let www_authenticate = response
+            .headers()
+            .get("WWW-Authenticate")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+
+        if let Some(challenge) = www_authenticate {
+            let (scheme, realm) = parse_www_authenticate(&challenge);
+            debug!("Desafío de autenticación recibido: scheme={}, realm={:?}", scheme, realm);
+
+            if scheme.eq_ignore_ascii_case("Basic") {
+                match (basic_auth_username, basic_auth_password) {
+                    (Some(username), Some(password)) if !username.is_empty() => {
+                        info!("🔁 Reintentando la petición con autenticación HTTP Basic");
+                        let credentials = general_purpose::STANDARD.encode(format!("{}:{}", username, password));
+
+                        let retry_response = client
+                            .post(&endpoint)
+                            .header("Content-Type", "application/json")
+                            .header("User-Agent", "SystemMonitor/1.0")
+                            .header("Authorization", format!("Basic {}", credentials))
+                            .header("X-SystemMonitor-Schema", TELEMETRY_SCHEMA_VERSION.to_string())
+                            .body(envelope_body)
+                            .send()
+                            .await;
+
+                        return match retry_response {
+                            Ok(retry_response) => {
+                                let retry_status = retry_response.status();
+                                if let Some(mismatch) = check_schema_mismatch(retry_response.headers()) {
+                                    warn!("{}", mismatch);
+                                    return Err(mismatch);
+                                }
+                                if retry_status.is_success() {
+                                    match retry_response.text().await {
+                                        Ok(body) => {
+                                            info!("✅ Datos enviados exitosamente a la API tras autenticación Basic");
+                                            Ok(body)
+                                        },
+                                        Err(e) => {
+                                            error!("Error leyendo respuesta: {}", e);
+                                            Err(format!("Error leyendo respuesta: {}", e))
+                                        }
+                                    }
+                                } else {
+                                    let error_msg = format!(
+                                        "HTTP {}: {}",
+                                        retry_status.as_u16(),
+                                        retry_status.canonical_reason().unwrap_or("Unknown")
+                                    );
+                                    error!("Error HTTP tras autenticación Basic: {}", error_msg);
+                                    Err(error_msg)
+                                }
+                            },
+                            Err(e) => {
+                                error!("Error en petición HTTP con autenticación Basic: {}", e);
+                                Err(format!("Error en petición: {}", e))
+                            }
+                        };
+                    }
+                    _ => {
+                        let error_msg = match realm {
+                            Some(realm) => format!("HTTP 401: se requiere autenticación Basic (realm=\"{}\")", realm),
+                            None => "HTTP 401: se requiere autenticación Basic".to_string(),
+                        };
+                        warn!("{}", error_msg);
+                        return Err(error_msg);
                     }
                 }
-            } else {
-                let error_msg = format!("HTTP {}: {}", status.as_u16(), status.canonical_reason().unwrap_or("Unknown"));
-                error!("Error HTTP: {}", error_msg);
-                Err(error_msg)
             }
-        },
+        }
+    }
+
+    let error_msg = format!("HTTP {}: {}", status.as_u16(), status.canonical_reason().unwrap_or("Unknown"));
+    error!("Error HTTP: {}", error_msg);
+    Err(error_msg)
+}
+
+// Revisa las cabeceras `X-SystemMonitor-Schema-Min`/`X-SystemMonitor-Schema-Max`
+// que el servidor puede anunciar, y devuelve un error `SchemaMismatch: ...`
+// si nuestra versión de esquema queda fuera del rango soportado.
+fn check_schema_mismatch(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let min_supported: Option<u32> = headers
+        .get("X-SystemMonitor-Schema-Min")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok());
+    let max_supported: Option<u32> = headers
+        .get("X-SystemMonitor-Schema-Max")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok());
+
+    if min_supported.is_none() && max_supported.is_none() {
+        return None;
+    }
+
+    let below_min = min_supported.is_some_and(|min| TELEMETRY_SCHEMA_VERSION < min);
+    let above_max = max_supported.is_some_and(|max| TELEMETRY_SCHEMA_VERSION > max);
+
+    if below_min || above_max {
+        Some(format!(
+            "SchemaMismatch: el servidor soporta las versiones de esquema {}-{}, pero el cliente envía la versión {}. Actualice la aplicación.",
+            min_supported.map(|v| v.to_string()).unwrap_or_else(|| "?".to_string()),
+            max_supported.map(|v| v.to_string()).unwrap_or_else(|| "?".to_string()),
+            TELEMETRY_SCHEMA_VERSION
+        ))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod schema_mismatch_tests {
+    use super::*;
+
+    fn headers(min: Option<&str>, max: Option<&str>) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(min) = min {
+            headers.insert(
+                "X-SystemMonitor-Schema-Min",
+                reqwest::header::HeaderValue::from_str(min).unwrap(),
+            );
+        }
+        if let Some(max) = max {
+            headers.insert(
+                "X-SystemMonitor-Schema-Max",
+                reqwest::header::HeaderValue::from_str(max).unwrap(),
+            );
+        }
+        headers
+    }
+
+    #[test]
+    fn no_headers_means_no_mismatch() {
+        assert!(check_schema_mismatch(&headers(None, None)).is_none());
+    }
+
+    #[test]
+    fn version_equal_to_min_is_not_a_mismatch() {
+        // TELEMETRY_SCHEMA_VERSION es 1: el servidor exige como mínimo 1.
+        assert!(check_schema_mismatch(&headers(Some("1"), None)).is_none());
+    }
+
+    #[test]
+    fn version_below_min_is_a_mismatch() {
+        let result = check_schema_mismatch(&headers(Some("2"), None));
+        assert!(result.is_some());
+        assert!(result.unwrap().starts_with("SchemaMismatch:"));
+    }
+
+    #[test]
+    fn version_equal_to_max_is_not_a_mismatch() {
+        assert!(check_schema_mismatch(&headers(None, Some("1"))).is_none());
+    }
+
+    #[test]
+    fn version_above_max_is_a_mismatch() {
+        let result = check_schema_mismatch(&headers(None, Some("0")));
+        assert!(result.is_some());
+        assert!(result.unwrap().starts_with("SchemaMismatch:"));
+    }
+
+    #[test]
+    fn unparsable_headers_are_treated_as_absent() {
+        assert!(check_schema_mismatch(&headers(Some("not-a-number"), Some("also-not-a-number"))).is_none());
+    }
+}
+
+// Parsea una cabecera `WWW-Authenticate`, p. ej. `Basic realm="My Realm"`,
+// devolviendo el esquema y, si está presente, el realm.
+fn parse_www_authenticate(header_value: &str) -> (String, Option<String>) {
+    let mut parts = header_value.splitn(2, ' ');
+    let scheme = parts.next().unwrap_or("").trim().to_string();
+    let params = parts.next().unwrap_or("");
+
+    let realm = params
+        .split(',')
+        .find_map(|param| {
+            let param = param.trim();
+            param.strip_prefix("realm=").map(|value| value.trim_matches('"').to_string())
+        });
+
+    (scheme, realm)
+}
+
+#[cfg(test)]
+mod www_authenticate_tests {
+    use super::*;
+
+    #[test]
+    fn parses_scheme_and_realm() {
+        let (scheme, realm) = parse_www_authenticate("Basic realm=\"My Realm\"");
+        assert_eq!(scheme, "Basic");
+        assert_eq!(realm, Some("My Realm".to_string()));
+    }
+
+    #[test]
+    fn finds_realm_among_multiple_params_in_any_order() {
+        let (scheme, realm) = parse_www_authenticate("Basic charset=\"UTF-8\", realm=\"My Realm\"");
+        assert_eq!(scheme, "Basic");
+        assert_eq!(realm, Some("My Realm".to_string()));
+    }
+
+    #[test]
+    fn missing_realm_returns_none() {
+        let (scheme, realm) = parse_www_authenticate("Digest");
+        assert_eq!(scheme, "Digest");
+        assert_eq!(realm, None);
+    }
+
+    #[test]
+    fn preserves_scheme_case_as_received() {
+        let (scheme, realm) = parse_www_authenticate("basic realm=\"My Realm\"");
+        assert_eq!(scheme, "basic");
+        assert_eq!(realm, Some("My Realm".to_string()));
+    }
+}
+
+// Comando para obtener un token OAuth2 mediante el flujo client-credentials
+#[tauri::command]
+async fn get_oauth_token(
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    scope: Option<String>,
+) -> Result<String, String> {
+    get_or_refresh_oauth_token(&token_url, &client_id, &client_secret, scope.as_deref()).await
+}
+
+// Devuelve el access token cacheado si sigue vigente (con margen de refresco),
+// o solicita uno nuevo al endpoint de token y lo cachea.
+async fn get_or_refresh_oauth_token(
+    token_url: &str,
+    client_id: &str,
+    client_secret: &str,
+    scope: Option<&str>,
+) -> Result<String, String> {
+    let cache = OAUTH_TOKEN_CACHE.get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+    let cache_key = (token_url.to_string(), client_id.to_string());
+
+    {
+        let cached = cache.lock().unwrap();
+        if let Some(cached_token) = cached.get(&cache_key) {
+            if cached_token.expires_at > std::time::Instant::now() + OAUTH_REFRESH_MARGIN {
+                debug!("♻️ Reutilizando token OAuth2 cacheado para {}", token_url);
+                return Ok(cached_token.access_token.clone());
+            }
+        }
+    }
+
+    info!("🔑 Solicitando nuevo token OAuth2 a {}", token_url);
+
+    let mut form = vec![
+        ("grant_type", "client_credentials"),
+        ("client_id", client_id),
+        ("client_secret", client_secret),
+    ];
+    if let Some(scope) = scope {
+        form.push(("scope", scope));
+    }
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(token_url)
+        .header("User-Agent", "SystemMonitor/1.0")
+        .form(&form)
+        .send()
+        .await
+        .map_err(|e| {
+            error!("Error solicitando token OAuth2: {}", e);
+            format!("Error solicitando token OAuth2: {}", e)
+        })?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_msg = format!("HTTP {}: {}", status.as_u16(), status.canonical_reason().unwrap_or("Unknown"));
+        error!("Error HTTP obteniendo token OAuth2: {}", error_msg);
+        return Err(error_msg);
+    }
+
+    let token_response: OAuthTokenResponse = response.json().await.map_err(|e| {
+        error!("Error parseando respuesta del token OAuth2: {}", e);
+        format!("Error parseando respuesta del token OAuth2: {}", e)
+    })?;
+
+    let cached_token = CachedToken {
+        access_token: token_response.access_token.clone(),
+        expires_at: std::time::Instant::now() + Duration::from_secs(token_response.expires_in),
+    };
+
+    cache.lock().unwrap().insert(cache_key, cached_token);
+    info!("✅ Token OAuth2 obtenido y cacheado, expira en {}s", token_response.expires_in);
+
+    Ok(token_response.access_token)
+}
+
+// Comando para encolar un payload de telemetría que no pudo (o no debe)
+// enviarse de inmediato, para que la tarea de flush en segundo plano lo
+// reintente cuando haya conectividad.
+#[tauri::command]
+async fn enqueue_telemetry(
+    endpoint: String,
+    payload: String,
+    token: Option<String>,
+    // Momento de recolección del payload; si el llamante no lo provee se usa
+    // el momento del encolado, que es lo más cercano a la recolección de lo
+    // que esta función puede saber.
+    collected_at: Option<String>,
+) -> Result<(), String> {
+    info!("📥 Encolando telemetría pendiente de envío para {}", endpoint);
+
+    let record = QueuedTelemetryRecord {
+        endpoint,
+        payload,
+        token,
+        collected_at: collected_at.unwrap_or_else(now_rfc3339),
+        attempts: 0,
+        next_attempt_at: now_rfc3339(),
+    };
+
+    append_telemetry_record(TELEMETRY_QUEUE_FILE, &record)
+}
+
+// Agrega una línea JSON al archivo indicado, creando el directorio `logs` si falta.
+// Serializado con `telemetry_queue_lock` para que no se intercale con el
+// vaciado/reescritura que hace `flush_telemetry_queue`.
+fn append_telemetry_record(path: &str, record: &QueuedTelemetryRecord) -> Result<(), String> {
+    use std::io::Write;
+
+    let _guard = telemetry_queue_lock().lock().unwrap();
+    let path = PathBuf::from(path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            error!("❌ No se pudo crear el directorio de la cola de telemetría: {}", e);
+            format!("No se pudo crear el directorio de la cola de telemetría: {}", e)
+        })?;
+    }
+
+    let line = serde_json::to_string(record).map_err(|e| {
+        error!("❌ No se pudo serializar el registro de telemetría: {}", e);
+        format!("No se pudo serializar el registro de telemetría: {}", e)
+    })?;
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| {
+            error!("❌ No se pudo abrir el archivo de cola de telemetría: {}", e);
+            format!("No se pudo abrir el archivo de cola de telemetría: {}", e)
+        })?;
+
+    writeln!(file, "{}", line).map_err(|e| {
+        error!("❌ No se pudo escribir en el archivo de cola de telemetría: {}", e);
+        format!("No se pudo escribir en el archivo de cola de telemetría: {}", e)
+    })
+}
+
+// Tarea en segundo plano que, mientras haya conectividad, drena la cola de
+// telemetría pendiente reintentando cada registro con backoff exponencial.
+async fn run_telemetry_flush_loop() {
+    loop {
+        tokio::time::sleep(TELEMETRY_FLUSH_INTERVAL).await;
+
+        if !check_internet_connectivity().await.unwrap_or(false) {
+            debug!("Sin conectividad, se pospone el drenado de la cola de telemetría");
+            continue;
+        }
+
+        flush_telemetry_queue().await;
+    }
+}
+
+// Drena la cola de telemetría por completo: intenta enviar cada registro
+// cuyo `next_attempt_at` ya venció, reencola los que fallan con backoff
+// exponencial para el siguiente drenado, y mueve los que agoten sus
+// intentos a la cola de mensajes muertos.
+async fn flush_telemetry_queue() {
+    let queue_path = PathBuf::from(TELEMETRY_QUEUE_FILE);
+
+    // Se toma la cola "en préstamo": se lee y se vacía el archivo bajo el
+    // mismo lock que usa `append_telemetry_record`, para que cualquier
+    // registro encolado mientras el drenado está en curso (que puede tardar
+    // por las peticiones HTTP de cada registro) caiga en un archivo limpio en
+    // vez de ser descartado por una reescritura final con una foto vieja.
+    let records: Vec<QueuedTelemetryRecord> = {
+        let _guard = telemetry_queue_lock().lock().unwrap();
+
+        let contents = match std::fs::read_to_string(&queue_path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+            Err(e) => {
+                error!("❌ No se pudo leer la cola de telemetría: {}", e);
+                return;
+            }
+        };
+
+        let records: Vec<QueuedTelemetryRecord> = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+
+        if records.is_empty() {
+            return;
+        }
+
+        if let Err(e) = std::fs::write(&queue_path, "") {
+            error!("❌ No se pudo vaciar la cola de telemetría antes de drenarla: {}", e);
+            return;
+        }
+
+        records
+    };
+
+    info!("📤 Drenando {} registros pendientes de la cola de telemetría", records.len());
+
+    // Un solo intento de envío por registro y por drenado: el backoff
+    // exponencial se logra diferiendo `next_attempt_at` entre drenados
+    // sucesivos (cada ~TELEMETRY_FLUSH_INTERVAL) en vez de bloquear esta
+    // tarea con `tokio::time::sleep` durante minutos. Así el contador de
+    // intentos persiste en disco entre drenados, tal como se pretendía.
+    for mut record in records {
+        if !telemetry_record_is_due(&record) {
+            if let Err(e) = append_telemetry_record(TELEMETRY_QUEUE_FILE, &record) {
+                error!("❌ No se pudo reencolar el registro de telemetría: {}", e);
+            }
+            continue;
+        }
+
+        match send_to_api(
+            record.endpoint.clone(),
+            record.payload.clone(),
+            Some(record.collected_at.clone()),
+            record.token.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        {
+            Ok(_) => {
+                info!("✅ Registro de telemetría encolado enviado exitosamente a {}", record.endpoint);
+            }
+            Err(e) => {
+                record.attempts += 1;
+                warn!(
+                    "❌ Intento {}/{} fallido para {}: {}",
+                    record.attempts, TELEMETRY_MAX_ATTEMPTS, record.endpoint, e
+                );
+
+                if record.attempts >= TELEMETRY_MAX_ATTEMPTS {
+                    error!(
+                        "💀 Registro de telemetría para {} agotó sus intentos, se mueve a la cola de mensajes muertos",
+                        record.endpoint
+                    );
+                    // No conservar el token de forma indefinida en un archivo
+                    // que nunca se limpia: se redacta antes de mover el registro.
+                    let mut dead_record = record.clone();
+                    dead_record.token = dead_record.token.map(|_| "<redacted>".to_string());
+                    if let Err(e) = append_telemetry_record(TELEMETRY_DEAD_LETTER_FILE, &dead_record) {
+                        error!("❌ No se pudo mover el registro a la cola de mensajes muertos: {}", e);
+                    }
+                } else {
+                    let backoff = Duration::from_secs(1 << (record.attempts - 1).min(31)).min(TELEMETRY_MAX_BACKOFF);
+                    record.next_attempt_at = rfc3339_after(backoff);
+                    if let Err(e) = append_telemetry_record(TELEMETRY_QUEUE_FILE, &record) {
+                        error!("❌ No se pudo reencolar el registro de telemetría: {}", e);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Indica si ya se cumplió `next_attempt_at` para este registro. Una marca
+// ilegible se trata como "ya vencida" para no bloquear el registro para siempre.
+fn telemetry_record_is_due(record: &QueuedTelemetryRecord) -> bool {
+    match chrono::DateTime::parse_from_rfc3339(&record.next_attempt_at) {
+        Ok(next_attempt_at) => chrono::Utc::now() >= next_attempt_at,
+        Err(_) => true,
+    }
+}
+
+// RFC3339 (segundos) correspondiente al momento actual más `offset`.
+fn rfc3339_after(offset: Duration) -> String {
+    let offset = chrono::Duration::from_std(offset).unwrap_or(chrono::Duration::zero());
+    (chrono::Utc::now() + offset).to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+// Comando para listar los vecinos de red detectados (tabla ARP/NDP)
+#[tauri::command]
+async fn get_network_neighbors() -> Result<Vec<NetworkNeighbor>, String> {
+    info!("🔍 Consultando tabla de vecinos de red (ARP/NDP)");
+
+    #[cfg(target_os = "windows")]
+    let output = Command::new("powershell")
+        .args(["-Command", "Get-NetNeighbor | Select-Object IPAddress,LinkLayerAddress,InterfaceAlias,State | ConvertTo-Csv -NoTypeInformation"])
+        .creation_flags(0x08000000) // CREATE_NO_WINDOW
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .stdin(Stdio::null())
+        .output();
+
+    #[cfg(not(target_os = "windows"))]
+    let output = Command::new("ip")
+        .args(["neigh"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .stdin(Stdio::null())
+        .output();
+
+    let output = match output {
+        Ok(result) => result,
         Err(e) => {
-            error!("Error en petición HTTP: {}", e);
-            Err(format!("Error en petición: {}", e))
+            error!("❌ Error ejecutando la consulta de vecinos de red: {}", e);
+            return Err(format!("Error ejecutando la consulta de vecinos de red: {}", e));
+        }
+    };
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        error!("❌ La consulta de vecinos de red falló: {}", stderr);
+        return Err(format!("La consulta de vecinos de red falló: {}", stderr));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    #[cfg(target_os = "windows")]
+    let neighbors = parse_net_neighbor_csv(&stdout);
+
+    #[cfg(not(target_os = "windows"))]
+    let neighbors = parse_ip_neigh(&stdout);
+
+    info!("✅ Se encontraron {} vecinos de red", neighbors.len());
+    Ok(neighbors)
+}
+
+// Parsea la salida CSV de `Get-NetNeighbor | ConvertTo-Csv -NoTypeInformation`:
+// una fila de cabecera seguida de filas con campos entre comillas.
+#[cfg(target_os = "windows")]
+fn parse_net_neighbor_csv(csv: &str) -> Vec<NetworkNeighbor> {
+    let mut lines = csv.lines();
+    lines.next(); // descartar la cabecera
+
+    lines
+        .filter_map(|line| {
+            let fields: Vec<String> = line
+                .split(',')
+                .map(|field| field.trim().trim_matches('"').to_string())
+                .collect();
+
+            if fields.len() < 4 {
+                return None;
+            }
+
+            let ip_address = fields[0].clone();
+            let mac_address = fields[1].clone();
+            let interface = fields[2].clone();
+            let state = fields[3].clone();
+
+            if mac_address.is_empty() || state.eq_ignore_ascii_case("Unreachable") {
+                debug!("Vecino de red descartado ({}): MAC vacía o estado Unreachable", ip_address);
+                return None;
+            }
+
+            Some(NetworkNeighbor {
+                ip_address,
+                mac_address,
+                interface,
+                state,
+            })
+        })
+        .collect()
+}
+
+// Parsea la salida de `ip neigh`, con líneas como:
+// "192.168.1.1 dev eth0 lladdr aa:bb:cc:dd:ee:ff REACHABLE"
+#[cfg(not(target_os = "windows"))]
+fn parse_ip_neigh(output: &str) -> Vec<NetworkNeighbor> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.is_empty() {
+                return None;
+            }
+
+            let ip_address = tokens[0].to_string();
+            let interface = tokens
+                .iter()
+                .position(|&t| t == "dev")
+                .and_then(|i| tokens.get(i + 1))
+                .unwrap_or(&"")
+                .to_string();
+            let mac_address = tokens
+                .iter()
+                .position(|&t| t == "lladdr")
+                .and_then(|i| tokens.get(i + 1))
+                .unwrap_or(&"")
+                .to_string();
+            let state = tokens.last().unwrap_or(&"").to_string();
+
+            if mac_address.is_empty() || state.eq_ignore_ascii_case("FAILED") {
+                debug!("Vecino de red descartado ({}): lladdr vacío o estado FAILED", ip_address);
+                return None;
+            }
+
+            Some(NetworkNeighbor {
+                ip_address,
+                mac_address,
+                interface,
+                state,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod network_neighbor_tests {
+    use super::*;
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn parses_quoted_csv_rows_and_skips_header() {
+        let csv = "\"IPAddress\",\"LinkLayerAddress\",\"InterfaceAlias\",\"State\"\n\
+                    \"192.168.1.1\",\"AA-BB-CC-DD-EE-FF\",\"Ethernet\",\"Reachable\"\n\
+                    \"fe80::1\",\"11-22-33-44-55-66\",\"Wi-Fi\",\"Stale\"";
+
+        let neighbors = parse_net_neighbor_csv(csv);
+
+        assert_eq!(neighbors.len(), 2);
+        assert_eq!(neighbors[0].ip_address, "192.168.1.1");
+        assert_eq!(neighbors[0].mac_address, "AA-BB-CC-DD-EE-FF");
+        assert_eq!(neighbors[0].interface, "Ethernet");
+        assert_eq!(neighbors[0].state, "Reachable");
+        assert_eq!(neighbors[1].ip_address, "fe80::1");
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn skips_rows_with_empty_mac_or_unreachable_state() {
+        let csv = "\"IPAddress\",\"LinkLayerAddress\",\"InterfaceAlias\",\"State\"\n\
+                    \"192.168.1.2\",\"\",\"Ethernet\",\"Incomplete\"\n\
+                    \"192.168.1.3\",\"AA-BB-CC-DD-EE-FF\",\"Ethernet\",\"Unreachable\"";
+
+        let neighbors = parse_net_neighbor_csv(csv);
+
+        assert!(neighbors.is_empty());
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn skips_partial_rows_missing_fields() {
+        let csv = "\"IPAddress\",\"LinkLayerAddress\",\"InterfaceAlias\",\"State\"\n\
+                    \"192.168.1.4\",\"AA-BB-CC-DD-EE-FF\"";
+
+        let neighbors = parse_net_neighbor_csv(csv);
+
+        assert!(neighbors.is_empty());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn parses_ip_neigh_line_with_lladdr() {
+        let output = "192.168.1.1 dev eth0 lladdr aa:bb:cc:dd:ee:ff REACHABLE";
+
+        let neighbors = parse_ip_neigh(output);
+
+        assert_eq!(neighbors.len(), 1);
+        assert_eq!(neighbors[0].ip_address, "192.168.1.1");
+        assert_eq!(neighbors[0].interface, "eth0");
+        assert_eq!(neighbors[0].mac_address, "aa:bb:cc:dd:ee:ff");
+        assert_eq!(neighbors[0].state, "REACHABLE");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn skips_ip_neigh_lines_missing_lladdr_or_failed() {
+        let output = "192.168.1.2 dev eth0  INCOMPLETE\n\
+                       192.168.1.3 dev eth0 lladdr aa:bb:cc:dd:ee:ff FAILED";
+
+        let neighbors = parse_ip_neigh(output);
+
+        assert!(neighbors.is_empty());
+    }
+}
+
+// Comando para escanear las redes inalámbricas visibles
+#[tauri::command]
+async fn scan_wifi_networks() -> Result<Vec<WifiNetwork>, String> {
+    info!("📶 Escaneando redes WiFi cercanas");
+
+    #[cfg(target_os = "windows")]
+    let output = Command::new("netsh")
+        .args(["wlan", "show", "networks", "mode=bssid"])
+        .creation_flags(0x08000000) // CREATE_NO_WINDOW
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .stdin(Stdio::null())
+        .output();
+
+    #[cfg(not(target_os = "windows"))]
+    let output = Command::new("nmcli")
+        .args(["-t", "-f", "SSID,SIGNAL,CHAN,SECURITY", "dev", "wifi"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .stdin(Stdio::null())
+        .output();
+
+    let output = match output {
+        Ok(result) => result,
+        Err(e) => {
+            error!("❌ Error ejecutando el escaneo de redes WiFi: {}", e);
+            return Err(format!("Error ejecutando el escaneo de redes WiFi: {}", e));
         }
+    };
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        error!("❌ El escaneo de redes WiFi falló: {}", stderr);
+        return Err(format!("El escaneo de redes WiFi falló: {}", stderr));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    #[cfg(target_os = "windows")]
+    let networks = parse_netsh_wlan_networks(&stdout);
+
+    #[cfg(not(target_os = "windows"))]
+    let networks = parse_nmcli_wifi(&stdout);
+
+    info!("✅ Se encontraron {} redes WiFi", networks.len());
+    Ok(networks)
+}
+
+// Parsea la salida de `netsh wlan show networks mode=bssid`, que agrupa la
+// información en bloques indentados "Clave : Valor" por cada SSID y, dentro
+// de cada uno, un sub-bloque por BSSID con su propia señal.
+#[cfg(target_os = "windows")]
+fn parse_netsh_wlan_networks(output: &str) -> Vec<WifiNetwork> {
+    let mut networks = Vec::new();
+
+    let mut current_ssid: Option<String> = None;
+    let mut current_auth = String::new();
+    let mut current_encryption = String::new();
+    let mut current_signal: u8 = 0;
+    let mut current_channel = String::new();
+
+    for line in output.lines() {
+        let line = line.trim();
+
+        if let Some(value) = line.strip_prefix("SSID ").and_then(|rest| rest.split_once(':').map(|(_, v)| v.trim())) {
+            current_ssid = Some(value.to_string());
+            current_auth.clear();
+            current_encryption.clear();
+            current_signal = 0;
+            current_channel.clear();
+        } else if let Some(value) = line.strip_prefix("Authentication") .and_then(|rest| rest.split_once(':').map(|(_, v)| v.trim())) {
+            current_auth = value.to_string();
+        } else if let Some(value) = line.strip_prefix("Encryption").and_then(|rest| rest.split_once(':').map(|(_, v)| v.trim())) {
+            current_encryption = value.to_string();
+        } else if let Some(value) = line.strip_prefix("Signal").and_then(|rest| rest.split_once(':').map(|(_, v)| v.trim())) {
+            current_signal = value.trim_end_matches('%').parse().unwrap_or(0);
+        } else if let Some(value) = line.strip_prefix("Channel").and_then(|rest| rest.split_once(':').map(|(_, v)| v.trim())) {
+            current_channel = value.to_string();
+
+            if let Some(ssid) = &current_ssid {
+                networks.push(WifiNetwork {
+                    ssid: ssid.clone(),
+                    signal_percent: current_signal,
+                    channel: current_channel.clone(),
+                    security: normalize_wifi_security(&current_auth, &current_encryption),
+                });
+            }
+        }
+    }
+
+    networks
+}
+
+// Normaliza las cadenas "Authentication"/"Encryption" reportadas por netsh
+// (p. ej. "WPA2-Personal" + "CCMP") a un enum simplificado.
+#[cfg(target_os = "windows")]
+fn normalize_wifi_security(authentication: &str, encryption: &str) -> String {
+    let auth = authentication.to_lowercase();
+    let enc = encryption.to_lowercase();
+
+    if auth.contains("wpa3") {
+        "WPA3".to_string()
+    } else if auth.contains("wpa2") {
+        "WPA2".to_string()
+    } else if auth.contains("wpa") {
+        "WPA".to_string()
+    } else if enc.contains("wep") {
+        // WEP se anuncia típicamente como Authentication: Open o Shared junto
+        // con Encryption: WEP; se detecta por el cifrado en vez de exigir
+        // "Open" para no perder el caso "Shared".
+        "WEP".to_string()
+    } else {
+        "Open".to_string()
+    }
+}
+
+// Parsea la salida de `nmcli -t -f SSID,SIGNAL,CHAN,SECURITY dev wifi`,
+// con campos separados por ':'.
+#[cfg(not(target_os = "windows"))]
+fn parse_nmcli_wifi(output: &str) -> Vec<WifiNetwork> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(':').collect();
+            if fields.len() < 4 {
+                return None;
+            }
+
+            Some(WifiNetwork {
+                ssid: fields[0].to_string(),
+                signal_percent: fields[1].parse().unwrap_or(0),
+                channel: fields[2].to_string(),
+                security: normalize_nmcli_security(fields[3]),
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "windows"))]
+fn normalize_nmcli_security(security: &str) -> String {
+    let security = security.to_lowercase();
+
+    if security.contains("wpa3") {
+        "WPA3".to_string()
+    } else if security.contains("wpa2") {
+        "WPA2".to_string()
+    } else if security.contains("wpa") {
+        "WPA".to_string()
+    } else if security.contains("wep") {
+        "WEP".to_string()
+    } else {
+        "Open".to_string()
+    }
+}
+
+#[cfg(test)]
+mod wifi_scan_tests {
+    use super::*;
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn parses_multiple_bssids_under_the_same_ssid() {
+        let output = "SSID 1 : HomeNetwork\n    \
+                      Network type            : Infrastructure\n    \
+                      Authentication          : WPA2-Personal\n    \
+                      Encryption              : CCMP\n    \
+                      BSSID 1                 : aa:bb:cc:dd:ee:01\n         \
+                      Signal                  : 80%\n         \
+                      Channel                 : 6\n    \
+                      BSSID 2                 : aa:bb:cc:dd:ee:02\n         \
+                      Signal                  : 55%\n         \
+                      Channel                 : 11";
+
+        let networks = parse_netsh_wlan_networks(output);
+
+        assert_eq!(networks.len(), 2);
+        assert_eq!(networks[0].ssid, "HomeNetwork");
+        assert_eq!(networks[0].signal_percent, 80);
+        assert_eq!(networks[0].channel, "6");
+        assert_eq!(networks[0].security, "WPA2");
+        assert_eq!(networks[1].ssid, "HomeNetwork");
+        assert_eq!(networks[1].signal_percent, 55);
+        assert_eq!(networks[1].channel, "11");
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn parses_open_network_with_no_trailing_bssid() {
+        let output = "SSID 1 : CoffeeShop\n    \
+                      Authentication          : Open\n    \
+                      Encryption              : None\n    \
+                      BSSID 1                 : aa:bb:cc:dd:ee:03\n         \
+                      Signal                  : 40%\n         \
+                      Channel                 : 1";
+
+        let networks = parse_netsh_wlan_networks(output);
+
+        assert_eq!(networks.len(), 1);
+        assert_eq!(networks[0].security, "Open");
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn normalizes_security_strings() {
+        assert_eq!(normalize_wifi_security("WPA2-Personal", "CCMP"), "WPA2");
+        assert_eq!(normalize_wifi_security("WPA3-SAE", "CCMP"), "WPA3");
+        assert_eq!(normalize_wifi_security("Open", "WEP"), "WEP");
+        // WEP se suele anunciar con autenticación "Shared" en vez de "Open".
+        assert_eq!(normalize_wifi_security("Shared", "WEP"), "WEP");
+        assert_eq!(normalize_wifi_security("Open", "None"), "Open");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn parses_nmcli_rows_and_skips_partial_ones() {
+        let output = "HomeNetwork:80:6:WPA2\n\
+                       CoffeeShop:40:1:--\n\
+                       Incomplete:55";
+
+        let networks = parse_nmcli_wifi(output);
+
+        assert_eq!(networks.len(), 2);
+        assert_eq!(networks[0].ssid, "HomeNetwork");
+        assert_eq!(networks[0].signal_percent, 80);
+        assert_eq!(networks[0].security, "WPA2");
+        assert_eq!(networks[1].ssid, "CoffeeShop");
+        assert_eq!(networks[1].security, "Open");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn normalizes_nmcli_security_strings() {
+        assert_eq!(normalize_nmcli_security("WPA2"), "WPA2");
+        assert_eq!(normalize_nmcli_security("WPA1 WPA2"), "WPA2");
+        assert_eq!(normalize_nmcli_security("WEP"), "WEP");
+        assert_eq!(normalize_nmcli_security("--"), "Open");
     }
 }
 
@@ -438,8 +1515,222 @@ async fn ping_host(host: &str) -> bool {
     }
 }
 
+// Intervalo entre recolecciones de telemetría cuando se ejecuta como servicio
+#[cfg(target_os = "windows")]
+const SERVICE_COLLECTION_INTERVAL: Duration = Duration::from_secs(300);
+
+// `tauri_plugin_log` solo se registra dentro de la cadena de `tauri::Builder`,
+// que nunca se ejecuta en modo servicio (se retorna antes). Sin un logger
+// propio aquí, todo `info!`/`warn!`/`error!` usado en el ciclo de vida del
+// servicio sería un no-op silencioso, dejando un servicio headless
+// indiagnosticable en campo.
+#[cfg(target_os = "windows")]
+struct ServiceFileLogger {
+    file: std::sync::Mutex<std::fs::File>,
+}
+
+#[cfg(target_os = "windows")]
+impl log::Log for ServiceFileLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        use std::io::Write;
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(
+                file,
+                "[{}] {} - {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        use std::io::Write;
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+// Inicializa un logger de archivo plano para el modo servicio, ya que la GUI
+// usa `tauri_plugin_log` y ese camino nunca se ejecuta aquí.
+#[cfg(target_os = "windows")]
+fn init_service_logger() {
+    let log_dir = PathBuf::from("logs");
+    if let Err(e) = std::fs::create_dir_all(&log_dir) {
+        eprintln!("No se pudo crear el directorio de logs del servicio: {}", e);
+        return;
+    }
+
+    let file = match std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_dir.join("system-monitor-service.log"))
+    {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("No se pudo abrir el archivo de log del servicio: {}", e);
+            return;
+        }
+    };
+
+    let logger = ServiceFileLogger {
+        file: std::sync::Mutex::new(file),
+    };
+
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(log::LevelFilter::Info);
+    }
+}
+
+#[cfg(target_os = "windows")]
+define_windows_service!(ffi_service_main, service_main);
+
+// Punto de entrada que Windows invoca al iniciar el servicio
+#[cfg(target_os = "windows")]
+fn service_main(_arguments: Vec<std::ffi::OsString>) {
+    if let Err(e) = run_service() {
+        error!("❌ El servicio finalizó con error: {}", e);
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn run_service() -> windows_service::Result<()> {
+    let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>();
+
+    let event_handler = move |control_event| -> ServiceControlHandlerResult {
+        match control_event {
+            ServiceControl::Stop | ServiceControl::Shutdown => {
+                info!("🛑 Señal de parada recibida por el servicio");
+                let _ = shutdown_tx.send(());
+                ServiceControlHandlerResult::NoError
+            }
+            ServiceControl::Interrogate => ServiceControlHandlerResult::NoError,
+            _ => ServiceControlHandlerResult::NotImplemented,
+        }
+    };
+
+    let status_handle = service_control_handler::register(SERVICE_NAME, event_handler)?;
+
+    status_handle.set_service_status(ServiceStatus {
+        service_type: ServiceType::OWN_PROCESS,
+        current_state: ServiceState::StartPending,
+        controls_accepted: ServiceControlAccept::empty(),
+        exit_code: ServiceExitCode::Win32(0),
+        checkpoint: 0,
+        wait_hint: Duration::default(),
+        process_id: None,
+    })?;
+
+    let runtime = tokio::runtime::Runtime::new().expect("No se pudo iniciar el runtime de tokio");
+
+    status_handle.set_service_status(ServiceStatus {
+        service_type: ServiceType::OWN_PROCESS,
+        current_state: ServiceState::Running,
+        controls_accepted: ServiceControlAccept::STOP | ServiceControlAccept::SHUTDOWN,
+        exit_code: ServiceExitCode::Win32(0),
+        checkpoint: 0,
+        wait_hint: Duration::default(),
+        process_id: None,
+    })?;
+
+    info!("✅ Servicio SystemMonitor en ejecución, recolectando telemetría cada {:?}", SERVICE_COLLECTION_INTERVAL);
+
+    loop {
+        runtime.block_on(collect_and_send_telemetry());
+
+        match shutdown_rx.recv_timeout(SERVICE_COLLECTION_INTERVAL) {
+            Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            Err(mpsc::RecvTimeoutError::Timeout) => continue,
+        }
+    }
+
+    status_handle.set_service_status(ServiceStatus {
+        service_type: ServiceType::OWN_PROCESS,
+        current_state: ServiceState::StopPending,
+        controls_accepted: ServiceControlAccept::empty(),
+        exit_code: ServiceExitCode::Win32(0),
+        checkpoint: 0,
+        wait_hint: Duration::default(),
+        process_id: None,
+    })?;
+
+    status_handle.set_service_status(ServiceStatus {
+        service_type: ServiceType::OWN_PROCESS,
+        current_state: ServiceState::Stopped,
+        controls_accepted: ServiceControlAccept::empty(),
+        exit_code: ServiceExitCode::Win32(0),
+        checkpoint: 0,
+        wait_hint: Duration::default(),
+        process_id: None,
+    })?;
+
+    info!("✅ Servicio SystemMonitor detenido correctamente");
+    Ok(())
+}
+
+// Recolecta la telemetría del equipo y la envía al endpoint configurado.
+// El endpoint/token se leen de variables de entorno porque el servicio
+// no tiene acceso a la configuración de la UI de Tauri.
+#[cfg(target_os = "windows")]
+async fn collect_and_send_telemetry() {
+    let basic_info = match get_basic_system_info().await {
+        Ok(info) => info,
+        Err(e) => {
+            error!("❌ No se pudo recolectar información básica en modo servicio: {}", e);
+            return;
+        }
+    };
+    let windows_info = match get_windows_specific_info().await {
+        Ok(info) => info,
+        Err(e) => {
+            error!("❌ No se pudo recolectar información de Windows en modo servicio: {}", e);
+            return;
+        }
+    };
+
+    // Se marca el momento de recolección aquí, inmediatamente después de
+    // reunir los datos, en vez de dejar que `send_to_api` use el momento del
+    // envío. Un envío fallido solo se registra en el log; no se encola.
+    let collected_at = now_rfc3339();
+
+    let endpoint = match std::env::var("SYSTEM_MONITOR_API_ENDPOINT") {
+        Ok(endpoint) => endpoint,
+        Err(_) => {
+            debug!("SYSTEM_MONITOR_API_ENDPOINT no configurado, se omite el envío de telemetría");
+            return;
+        }
+    };
+    let token = std::env::var("SYSTEM_MONITOR_API_TOKEN").ok();
+
+    let payload = serde_json::json!({
+        "basic_info": basic_info,
+        "windows_info": windows_info,
+    })
+    .to_string();
+
+    match send_to_api(endpoint, payload, Some(collected_at), token, None, None, None, None, None, None).await {
+        Ok(_) => info!("✅ Telemetría enviada desde el servicio"),
+        Err(e) => error!("❌ Error enviando telemetría desde el servicio: {}", e),
+    }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Modo servicio: se activa con `--service` y no lanza la GUI de Tauri
+    #[cfg(target_os = "windows")]
+    if std::env::args().any(|arg| arg == "--service") {
+        init_service_logger();
+        if let Err(e) = service_dispatcher::start(SERVICE_NAME, ffi_service_main) {
+            error!("❌ No se pudo iniciar el despachador de servicio: {}", e);
+        }
+        return;
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(
@@ -455,12 +1746,21 @@ pub fn run() {
             ])
             .build(),
         )
+        .setup(|_app| {
+            // Lanzar la tarea de drenado de la cola de telemetría en segundo plano
+            tauri::async_runtime::spawn(run_telemetry_flush_loop());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             get_basic_system_info,
             get_windows_specific_info,
             check_internet_connectivity,
-            send_to_api
+            send_to_api,
+            enqueue_telemetry,
+            get_network_neighbors,
+            scan_wifi_networks,
+            get_oauth_token
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");